@@ -1,6 +1,6 @@
 extern crate hashtag;
 
-use hashtag::Hashtag;
+use hashtag::HashtagParser;
 use std::fs::File;
 use std::io::prelude::*;
 use std::time::Instant;
@@ -15,8 +15,7 @@ pub fn main() {
     let mut count = 0;
     let start = Instant::now();
     for line in contents.lines() {
-        let tags = Hashtag::parse(line);
-        count += tags.len();
+        count += HashtagParser::new(line).count();
     }
     let duration = start.elapsed();
 
@@ -0,0 +1,738 @@
+//! The general entity-parsing machinery that powers [`HashtagParser`](crate::HashtagParser).
+//!
+//! [`EntityParser`] recognizes hashtags, @mentions, and cashtags in a single left-to-right pass
+//! by parameterizing the same tokenizer and state machine over which trigger character started
+//! the entity currently being parsed.
+
+use std::{borrow::Cow, fmt, iter::Peekable};
+
+/// An entity found in some text: a [`Hashtag`](crate::Hashtag), a [`Mention`], or a [`Cashtag`].
+///
+/// Yielded by [`EntityParser`]. [`HashtagParser`](crate::HashtagParser) is a filter over this
+/// that only keeps the [`Entity::Hashtag`] variant.
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Entity<'a> {
+    /// A `#hashtag`.
+    Hashtag(crate::Hashtag<'a>),
+    /// An `@mention`.
+    Mention(Mention<'a>),
+    /// A `$cashtag`.
+    Cashtag(Cashtag<'a>),
+}
+
+/// An `@mention` found in some text. See documentation of top level module for more info.
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Mention<'a> {
+    /// The text of the mention, without the leading `@`.
+    ///
+    /// If the mention is `"@rust"` the text will be `"rust"`.
+    pub text: Cow<'a, str>,
+
+    /// The starting index of the mention.
+    ///
+    /// This includes the `@` character. If the full text we're parsing is `"@rust"` then
+    /// `start` will be 0.
+    pub start: usize,
+
+    /// The ending index of the mention, inclusive.
+    ///
+    /// If the full text we're parsing is `"@rust"` then `end` will be 4.
+    pub end: usize,
+}
+
+impl<'a> fmt::Display for Mention<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}", self.text)
+    }
+}
+
+impl<'a> AsRef<str> for Mention<'a> {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A `$cashtag` found in some text. See documentation of top level module for more info.
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Cashtag<'a> {
+    /// The text of the cashtag, without the leading `$`.
+    ///
+    /// If the cashtag is `"$rust"` the text will be `"rust"`.
+    pub text: Cow<'a, str>,
+
+    /// The starting index of the cashtag.
+    ///
+    /// This includes the `$` character. If the full text we're parsing is `"$rust"` then
+    /// `start` will be 0.
+    pub start: usize,
+
+    /// The ending index of the cashtag, inclusive.
+    ///
+    /// If the full text we're parsing is `"$rust"` then `end` will be 4.
+    pub end: usize,
+}
+
+impl<'a> fmt::Display for Cashtag<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "${}", self.text)
+    }
+}
+
+impl<'a> AsRef<str> for Cashtag<'a> {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A parser that finds hashtags, @mentions, and cashtags in a string.
+///
+/// Implements [`Iterator`] and yields [`Entity`]s.
+#[derive(Debug)]
+pub struct EntityParser<'a> {
+    whole_string: &'a str,
+    state: IterState<'a>,
+    done: bool,
+}
+
+impl<'a> EntityParser<'a> {
+    /// Create a new `EntityParser` that will parse the given string.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            whole_string: text,
+            done: false,
+            state: IterState::Init,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum IterState<'a> {
+    Init,
+    Parsing {
+        tokens: Peekable<Tokens<'a>>,
+        stm: ParsingStateMachine<'a>,
+    },
+}
+
+impl<'a> Iterator for EntityParser<'a> {
+    type Item = Entity<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+
+            match &mut self.state {
+                IterState::Init => {
+                    let tokens = Tokens::new(self.whole_string).peekable();
+                    let stm = ParsingStateMachine::new(self.whole_string);
+                    self.state = IterState::Parsing { tokens, stm };
+                }
+                IterState::Parsing { tokens, stm } => {
+                    while let Some((i, byte_i, token)) = tokens.next() {
+                        match token {
+                            token @ (Token::Hash | Token::At | Token::Dollar) => {
+                                if let Some(kind) = stm.kind() {
+                                    if tokens.peek().map(|(_, _, tok)| tok).is_end_of(kind) {
+                                        stm.reset_parsing_state();
+                                    } else {
+                                        let new_kind = token
+                                            .trigger_kind()
+                                            .expect("matched arm is always a trigger token");
+                                        stm.token_seen_at(i - 1, byte_i, new_kind);
+                                    }
+                                }
+                            }
+
+                            Token::Whitespace => {
+                                let mut entity = None;
+
+                                if let Some(kind) = stm.kind() {
+                                    entity = stm.finishes_at(i - 2, kind);
+                                }
+
+                                if let Some(next_kind) =
+                                    tokens.peek().map(|(_, _, tok)| tok).trigger_kind()
+                                {
+                                    stm.incoming(next_kind);
+                                }
+
+                                if let Some(entity) = entity.take() {
+                                    return Some(entity);
+                                }
+                            }
+
+                            Token::Char(c) => {
+                                if let Some(kind) = stm.kind() {
+                                    let mut entity = None;
+
+                                    if c.is_end_of(kind) {
+                                        entity = stm.finishes_at(i - 2, kind);
+                                    } else {
+                                        stm.consume_char(c, byte_i);
+                                    }
+
+                                    if let Some(next_kind) =
+                                        tokens.peek().map(|(_, _, tok)| tok).trigger_kind()
+                                    {
+                                        entity = stm.finishes_at(i - 1, kind);
+                                        stm.incoming(next_kind);
+                                    }
+
+                                    if let Some(entity) = entity.take() {
+                                        return Some(entity);
+                                    }
+                                }
+                            }
+
+                            // A maximal run of plain ASCII bytes between two "interesting"
+                            // bytes (see `fast_scan`), produced only when the `simd` feature
+                            // fast-forwards past text that can't start or continue an entity.
+                            // Outside an entity it's a pure skip; inside one it's handled the
+                            // same way a sequence of `Token::Char`s would be, just batched.
+                            Token::Run(s) => {
+                                if let Some(kind) = stm.kind() {
+                                    let first_idx = i - 1;
+
+                                    if let Some((k, c)) =
+                                        s.char_indices().find(|&(_, c)| c.is_end_of(kind))
+                                    {
+                                        stm.consume_run_prefix(byte_i, k);
+                                        let mut entity = stm.finishes_at(first_idx + k - 1, kind);
+
+                                        // Only when the end-of-entity char is also the run's
+                                        // last char does the next `Tokens` item line up with
+                                        // what would've been the very next `Token::Char` in the
+                                        // non-`simd` tokenization; mirror that branch's
+                                        // unconditional peek-ahead in that case only, so a
+                                        // trigger immediately following still overwrites the
+                                        // entity the same way it does without `simd`.
+                                        if k + c.len_utf8() == s.len() {
+                                            if let Some(next_kind) =
+                                                tokens.peek().map(|(_, _, tok)| tok).trigger_kind()
+                                            {
+                                                entity = stm.finishes_at(first_idx + k, kind);
+                                                stm.incoming(next_kind);
+                                            }
+                                        }
+
+                                        if let Some(entity) = entity {
+                                            return Some(entity);
+                                        }
+                                    } else {
+                                        let char_len = s.chars().count();
+                                        stm.consume_run_prefix(byte_i, char_len);
+                                        let last_idx = first_idx + char_len - 1;
+
+                                        if let Some(next_kind) =
+                                            tokens.peek().map(|(_, _, tok)| tok).trigger_kind()
+                                        {
+                                            let entity = stm.finishes_at(last_idx, kind);
+                                            stm.incoming(next_kind);
+                                            if let Some(entity) = entity {
+                                                return Some(entity);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            Token::StartOfString => {
+                                if let Some(next_kind) =
+                                    tokens.peek().map(|(_, _, tok)| tok).trigger_kind()
+                                {
+                                    stm.incoming(next_kind);
+                                }
+                            }
+
+                            Token::EndOfString => {
+                                let entity = if let Some(kind) = stm.kind() {
+                                    stm.finishes_at(i - 2, kind)
+                                } else {
+                                    None
+                                };
+                                self.done = true;
+                                return entity;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> std::iter::FusedIterator for EntityParser<'a> {}
+
+/// Which trigger character started the entity currently being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TriggerKind {
+    Hashtag,
+    Mention,
+    Cashtag,
+}
+
+impl TriggerKind {
+    #[inline]
+    fn wrap(self, text: Cow<'_, str>, start: usize, end: usize) -> Entity<'_> {
+        match self {
+            TriggerKind::Hashtag => Entity::Hashtag(crate::Hashtag { text, start, end }),
+            TriggerKind::Mention => Entity::Mention(Mention { text, start, end }),
+            TriggerKind::Cashtag => Entity::Cashtag(Cashtag { text, start, end }),
+        }
+    }
+}
+
+/// Tracks enough state to slice the entity currently being parsed straight out of
+/// `whole_string` by byte offsets, so no copying is needed regardless of whether the input is
+/// ASCII. `start_index` is the entity's char-based position, carried alongside the byte offsets
+/// purely to fill in [`Hashtag`](crate::Hashtag)'s (and [`Mention`]'s / [`Cashtag`]'s)
+/// char-indexed public `start`/`end` fields.
+#[derive(Debug)]
+struct ParsingStateMachine<'a> {
+    consumed_anything: bool,
+    start_index: usize,
+    start_byte: usize,
+    end_byte: usize,
+    kind: Option<TriggerKind>,
+    whole_string: &'a str,
+}
+
+impl<'a> ParsingStateMachine<'a> {
+    #[inline]
+    fn new(text: &'a str) -> ParsingStateMachine<'a> {
+        ParsingStateMachine {
+            kind: None,
+            start_index: 0,
+            start_byte: 0,
+            end_byte: 0,
+            consumed_anything: false,
+            whole_string: text,
+        }
+    }
+
+    #[inline]
+    fn kind(&self) -> Option<TriggerKind> {
+        self.kind
+    }
+
+    #[inline]
+    fn token_seen_at(&mut self, idx: usize, byte_idx: usize, kind: TriggerKind) {
+        self.start_index = idx;
+        // The trigger character ('#', '@', '$') is always a single ASCII byte, so the
+        // content's first byte always directly follows it.
+        self.start_byte = byte_idx + 1;
+        self.kind = Some(kind);
+    }
+
+    #[inline]
+    fn finishes_at(&mut self, idx: usize, kind: TriggerKind) -> Option<Entity<'a>> {
+        let entity = if self.consumed_anything {
+            let text = Cow::Borrowed(&self.whole_string[self.start_byte..self.end_byte]);
+            Some(kind.wrap(text, self.start_index, idx))
+        } else {
+            None
+        };
+        self.reset_parsing_state();
+        entity
+    }
+
+    #[inline]
+    fn reset_parsing_state(&mut self) {
+        self.kind = None;
+        self.start_index = 0;
+        self.start_byte = 0;
+        self.end_byte = 0;
+        self.consumed_anything = false;
+    }
+
+    #[inline]
+    fn incoming(&mut self, kind: TriggerKind) {
+        self.kind = Some(kind);
+    }
+
+    #[inline]
+    fn consume_char(&mut self, c: char, byte_idx: usize) {
+        self.end_byte = byte_idx + c.len_utf8();
+        self.consumed_anything = true;
+    }
+
+    /// Like [`Self::consume_char`], but for a whole run of `char_count` plain chars starting at
+    /// `run_start_byte` and known not to contain any end-of-entity char. A no-op when
+    /// `char_count` is 0 (an empty run never happens in practice, but this keeps the method
+    /// honest about what it claims).
+    #[inline]
+    fn consume_run_prefix(&mut self, run_start_byte: usize, char_count: usize) {
+        if char_count > 0 {
+            self.end_byte = run_start_byte + char_count;
+            self.consumed_anything = true;
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+enum Token<'a> {
+    Char(char),
+    /// A maximal run of plain ASCII bytes that can't start, end, or interrupt an entity, only
+    /// ever produced by [`Tokens`] when the `simd` feature is enabled. See [`crate::fast_scan`].
+    ///
+    /// Never constructed without that feature, so the match arms handling it are unreachable
+    /// (and harmless) on the default feature set; only the variant itself needs an explicit
+    /// dead-code allowance since clippy can't see that through `cfg`.
+    #[cfg_attr(not(feature = "simd"), allow(dead_code))]
+    Run(&'a str),
+    Whitespace,
+    Hash,
+    At,
+    Dollar,
+    StartOfString,
+    EndOfString,
+}
+
+trait TriggerTokenKind {
+    fn trigger_kind(&self) -> Option<TriggerKind>;
+}
+
+impl<'a> TriggerTokenKind for Token<'a> {
+    #[inline]
+    fn trigger_kind(&self) -> Option<TriggerKind> {
+        match self {
+            Token::Hash => Some(TriggerKind::Hashtag),
+            Token::At => Some(TriggerKind::Mention),
+            Token::Dollar => Some(TriggerKind::Cashtag),
+            _ => None,
+        }
+    }
+}
+
+impl<T> TriggerTokenKind for Option<&T>
+where
+    T: TriggerTokenKind,
+{
+    #[inline]
+    fn trigger_kind(&self) -> Option<TriggerKind> {
+        self.and_then(|x| x.trigger_kind())
+    }
+}
+
+#[inline]
+fn token_from_char(c: char) -> Token<'static> {
+    match c {
+        '#' => Token::Hash,
+        '@' => Token::At,
+        '$' => Token::Dollar,
+        _ if c.is_hashtag_whitespace() => Token::Whitespace,
+        _ => Token::Char(c),
+    }
+}
+
+/// Whether a `char` tokenizes as [`Token::Whitespace`], i.e. unconditionally arms a following
+/// trigger the same way [`Token::StartOfString`] does. Ordinary punctuation in
+/// [`IsEndOfHashtag`] does *not* have this property: it only arms a trigger when it's also
+/// closing an entity already in progress, not on its own.
+pub(crate) trait IsHashtagWhitespace {
+    fn is_hashtag_whitespace(&self) -> bool;
+}
+
+impl IsHashtagWhitespace for char {
+    #[inline]
+    fn is_hashtag_whitespace(&self) -> bool {
+        matches!(self, ' ' | '\n' | '\r' | '\t')
+    }
+}
+
+/// Tokenizes a string one `(char_index, byte_index, Token)` triple at a time, where
+/// `char_index` is 1-based with 0 reserved for [`Token::StartOfString`] (so it lines up with the
+/// `i - 1` / `i - 2` arithmetic in [`EntityParser::next`]) and `byte_index` is the token's byte
+/// offset into the original string.
+///
+/// With the `simd` feature enabled, runs of plain ASCII bytes between "interesting" bytes (see
+/// [`crate::fast_scan`]) are coalesced into a single [`Token::Run`] instead of one
+/// [`Token::Char`] per character, which is what lets large hashtag-sparse inputs skip the
+/// per-char match in the hot loop.
+#[derive(Debug)]
+struct Tokens<'a> {
+    text: &'a str,
+    byte_pos: usize,
+    char_pos: usize,
+    start_emitted: bool,
+    end_emitted: bool,
+}
+
+impl<'a> Tokens<'a> {
+    #[inline]
+    fn new(text: &'a str) -> Self {
+        Tokens {
+            text,
+            byte_pos: 0,
+            char_pos: 0,
+            start_emitted: false,
+            end_emitted: false,
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn next_run(&mut self) -> Option<(usize, usize, Token<'a>)> {
+        let end = crate::fast_scan::scan_plain_run(self.text.as_bytes(), self.byte_pos);
+        let len = end - self.byte_pos;
+        // A run of a single char carries no advantage over `Token::Char` and would just add a
+        // branch to every other call site, so only coalesce runs of two or more.
+        if len < 2 {
+            return None;
+        }
+        let run = &self.text[self.byte_pos..end];
+        let item = (self.char_pos + 1, self.byte_pos, Token::Run(run));
+        self.byte_pos = end;
+        self.char_pos += len;
+        Some(item)
+    }
+
+    #[inline]
+    fn next_single_char(&mut self) -> Option<(usize, usize, Token<'a>)> {
+        let c = self.text[self.byte_pos..].chars().next()?;
+        let item = (self.char_pos + 1, self.byte_pos, token_from_char(c));
+        self.byte_pos += c.len_utf8();
+        self.char_pos += 1;
+        Some(item)
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = (usize, usize, Token<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.start_emitted {
+            self.start_emitted = true;
+            return Some((0, 0, Token::StartOfString));
+        }
+
+        if self.byte_pos >= self.text.len() {
+            if self.end_emitted {
+                return None;
+            }
+            self.end_emitted = true;
+            return Some((self.char_pos + 1, self.text.len(), Token::EndOfString));
+        }
+
+        #[cfg(feature = "simd")]
+        if let Some(run) = self.next_run() {
+            return Some(run);
+        }
+
+        self.next_single_char()
+    }
+}
+
+/// Whether a `char` ends a hashtag or mention (they share the same end-of-token rules).
+pub(crate) trait IsEndOfHashtag {
+    fn is_end_of_hashtag(&self) -> bool;
+}
+
+impl IsEndOfHashtag for char {
+    #[inline]
+    fn is_end_of_hashtag(&self) -> bool {
+        match self {
+            &'\'' | &' ' | &'%' | &'#' | &'\n' | &'"' | &'\t' | &'!' | &'@' | &'€' | &'$'
+            | &'^' | &'&' | &'*' | &'(' | &')' | &'\r' | &'.' | &',' | &'-' | &'<' | &'>'
+            | &'/' | &'\\' | &'|' | &'[' | &']' | &'{' | &'}' | &'`' | &'~' | &'=' | &'+'
+            | &';' | &'?' | &'£' | &'•' | &'´' | &':' => true,
+            &'_' => false,
+            _ => false,
+        }
+    }
+}
+
+/// Whether a `char` or `Token` ends the entity currently being parsed, which depends on which
+/// trigger character started it: cashtags only allow ASCII letters in their body, while
+/// hashtags and mentions share the punctuation-based [`IsEndOfHashtag`] rules.
+trait IsEndOf {
+    fn is_end_of(&self, kind: TriggerKind) -> bool;
+}
+
+impl IsEndOf for char {
+    #[inline]
+    fn is_end_of(&self, kind: TriggerKind) -> bool {
+        match kind {
+            TriggerKind::Hashtag | TriggerKind::Mention => self.is_end_of_hashtag(),
+            TriggerKind::Cashtag => !self.is_ascii_alphabetic(),
+        }
+    }
+}
+
+impl<'a> IsEndOf for Token<'a> {
+    #[inline]
+    fn is_end_of(&self, kind: TriggerKind) -> bool {
+        match self {
+            Token::Whitespace | Token::EndOfString => true,
+            Token::Char(c) => c.is_end_of(kind),
+            // A run can only ever be produced from plain, non-triggering bytes (see
+            // `fast_scan::is_interesting_byte`), so its first char settles whether it ends the
+            // entity the same way a lone `Token::Char` would.
+            Token::Run(s) => s
+                .chars()
+                .next()
+                .map(|c| c.is_end_of(kind))
+                .unwrap_or(true),
+            Token::StartOfString => false,
+            Token::Hash | Token::At | Token::Dollar => matches!(kind, TriggerKind::Cashtag),
+        }
+    }
+}
+
+impl<T> IsEndOf for Option<&T>
+where
+    T: IsEndOf,
+{
+    #[inline]
+    fn is_end_of(&self, kind: TriggerKind) -> bool {
+        self.map(|x| x.is_end_of(kind)).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "simd"))]
+    #[test]
+    fn test_tokenization() {
+        let tokens: Vec<(usize, usize, Token)> = Tokens::new("text #foo").collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (0, 0, Token::StartOfString),
+                (1, 0, Token::Char('t')),
+                (2, 1, Token::Char('e')),
+                (3, 2, Token::Char('x')),
+                (4, 3, Token::Char('t')),
+                (5, 4, Token::Whitespace),
+                (6, 5, Token::Hash),
+                (7, 6, Token::Char('f')),
+                (8, 7, Token::Char('o')),
+                (9, 8, Token::Char('o')),
+                (10, 9, Token::EndOfString),
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "simd"))]
+    #[test]
+    fn test_tokenize_strings_with_emojis() {
+        assert_eq!(
+            Tokens::new("😀").collect::<Vec<_>>(),
+            vec![
+                (0, 0, Token::StartOfString),
+                (1, 0, Token::Char('😀')),
+                (2, 4, Token::EndOfString),
+            ]
+        );
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_runs_are_coalesced_around_triggers() {
+        let tokens: Vec<(usize, usize, Token)> = Tokens::new("text #foo").collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (0, 0, Token::StartOfString),
+                (1, 0, Token::Run("text")),
+                (5, 4, Token::Whitespace),
+                (6, 5, Token::Hash),
+                (7, 6, Token::Run("foo")),
+                (10, 9, Token::EndOfString),
+            ]
+        );
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_single_char_runs_stay_as_char_tokens() {
+        // A run shorter than two chars brings no benefit, so it's left as `Token::Char`.
+        let tokens: Vec<(usize, usize, Token)> = Tokens::new("a #b").collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (0, 0, Token::StartOfString),
+                (1, 0, Token::Char('a')),
+                (2, 1, Token::Whitespace),
+                (3, 2, Token::Hash),
+                (4, 3, Token::Char('b')),
+                (5, 4, Token::EndOfString),
+            ]
+        );
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_entities_survive_run_coalescing() {
+        use crate::Hashtag;
+
+        let entities: Vec<Entity> = EntityParser::new("hello #rust world @you more $cash!").collect();
+        assert_eq!(
+            entities,
+            vec![
+                Entity::Hashtag(Hashtag {
+                    text: Cow::from("rust"),
+                    start: 6,
+                    end: 10,
+                }),
+                Entity::Mention(Mention {
+                    text: Cow::from("you"),
+                    start: 18,
+                    end: 21,
+                }),
+                Entity::Cashtag(Cashtag {
+                    text: Cow::from("cash"),
+                    start: 28,
+                    end: 32,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_hashtag_is_borrowed() {
+        let text = "café #rüst yay";
+        let hashtag = EntityParser::new(text).next().unwrap();
+        match hashtag {
+            Entity::Hashtag(hashtag) => {
+                assert_eq!(hashtag.text, Cow::Borrowed("rüst"));
+                assert!(matches!(hashtag.text, Cow::Borrowed(_)));
+            }
+            other => panic!("expected a hashtag, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_mentions_and_cashtags() {
+        use crate::Hashtag;
+
+        let entities: Vec<Entity> = EntityParser::new("#rust @you $rust").collect();
+        assert_eq!(
+            entities,
+            vec![
+                Entity::Hashtag(Hashtag {
+                    text: Cow::from("rust"),
+                    start: 0,
+                    end: 4,
+                }),
+                Entity::Mention(Mention {
+                    text: Cow::from("you"),
+                    start: 6,
+                    end: 9,
+                }),
+                Entity::Cashtag(Cashtag {
+                    text: Cow::from("rust"),
+                    start: 11,
+                    end: 15,
+                }),
+            ]
+        );
+    }
+}
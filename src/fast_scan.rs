@@ -0,0 +1,124 @@
+//! A coarse byte scan used by the `simd` feature to skip past runs of ordinary text between
+//! hashtags, mentions, and cashtags without running the full tokenizer one char at a time.
+//!
+//! [`scan_plain_run`] checks a whole `usize`-sized word of bytes at once using SWAR ("SIMD
+//! within a register") bit tricks instead of a per-byte loop, the same technique hand-rolled
+//! `memchr`-style searches use when pulling in a crate (and its platform-specific intrinsics)
+//! isn't an option. A real run of plain text is the common case for hashtag-sparse input, so most
+//! of a scan only ever touches the fast word-at-a-time path; the scalar loop only has to handle
+//! the last, less-than-a-word tail.
+
+/// Bytes whose high bit is set in every lane, used to test a whole word for any non-ASCII byte
+/// in one op: `word & NON_ASCII_MASK != 0` iff some byte in `word` has its top bit set.
+const NON_ASCII_MASK: usize = usize::from_ne_bytes([0x80; size_of::<usize>()]);
+
+/// Bytes whose low bit is set in every lane, the other half of the classic branchless
+/// has-zero-byte trick (see [`contains_byte`]).
+const LOW_BITS_MASK: usize = usize::from_ne_bytes([0x01; size_of::<usize>()]);
+
+/// Whether `b` can start, end, or interrupt an entity, and therefore has to stop a run.
+#[inline]
+pub(crate) fn is_interesting_byte(b: u8) -> bool {
+    matches!(b, b'#' | b'@' | b'$' | b' ' | b'\n' | b'\r' | b'\t')
+}
+
+/// `word` repeated into every byte lane of a `usize`, so it can be XORed against a word of input
+/// to turn "does this word contain a `target` byte" into "does this word contain a zero byte".
+#[inline]
+fn splat(target: u8) -> usize {
+    (target as usize) * LOW_BITS_MASK
+}
+
+/// Whether any byte lane of `word` equals `target`, checked for the whole word at once.
+///
+/// XORing `word` against `target` splatted into every lane turns a byte lane that matched
+/// `target` into a zero byte; the rest is the standard branchless has-zero-byte test, which
+/// relies on a zero byte being the only way subtracting one from it can borrow into its own top
+/// bit while that same top bit started out clear.
+#[inline]
+fn contains_byte(word: usize, target: u8) -> bool {
+    let v = word ^ splat(target);
+    v.wrapping_sub(LOW_BITS_MASK) & !v & NON_ASCII_MASK != 0
+}
+
+/// The interesting bytes, checked one word at a time by [`scan_plain_run`]'s fast path.
+const INTERESTING_BYTES: [u8; 7] = [b'#', b'@', b'$', b' ', b'\n', b'\r', b'\t'];
+
+/// The byte offset, starting from `from`, of the next interesting byte or non-ASCII byte in
+/// `bytes`, whichever comes first. Stopping at non-ASCII bytes keeps every byte in the returned
+/// run a single-byte, single-char `char`, so the caller can turn a run's length in bytes
+/// directly into its length in chars.
+#[inline]
+pub(crate) fn scan_plain_run(bytes: &[u8], from: usize) -> usize {
+    let word_size = size_of::<usize>();
+    let mut i = from;
+
+    while i + word_size <= bytes.len() {
+        let word = usize::from_ne_bytes(
+            bytes[i..i + word_size]
+                .try_into()
+                .expect("slice is exactly word_size bytes long"),
+        );
+
+        if word & NON_ASCII_MASK != 0 || INTERESTING_BYTES.iter().any(|&b| contains_byte(word, b))
+        {
+            break;
+        }
+
+        i += word_size;
+    }
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if !b.is_ascii() || is_interesting_byte(b) {
+            break;
+        }
+        i += 1;
+    }
+
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_at_the_first_interesting_byte() {
+        assert_eq!(scan_plain_run(b"helloworld #rust", 0), 10);
+    }
+
+    #[test]
+    fn stops_at_the_first_non_ascii_byte() {
+        assert_eq!(scan_plain_run("café".as_bytes(), 0), 3);
+    }
+
+    #[test]
+    fn runs_starting_mid_slice_are_measured_from_there() {
+        assert_eq!(scan_plain_run(b"### hello", 3), 3);
+        assert_eq!(scan_plain_run(b"### hello", 4), 9);
+    }
+
+    #[test]
+    fn a_run_with_no_interesting_byte_reaches_the_end_of_the_slice() {
+        assert_eq!(scan_plain_run(b"helloworld", 0), 10);
+    }
+
+    #[test]
+    fn an_interesting_byte_exactly_on_a_word_boundary_is_still_found() {
+        // Exercises the fast path's word-at-a-time check landing exactly on the byte that should
+        // stop the run, for every possible position within a word.
+        let word_size = size_of::<usize>();
+        for pos in 0..word_size * 3 {
+            let mut bytes = vec![b'a'; word_size * 3];
+            bytes[pos] = b'#';
+            assert_eq!(scan_plain_run(&bytes, 0), pos, "pos = {pos}");
+        }
+    }
+
+    #[test]
+    fn empty_slice_and_empty_remainder_stay_in_bounds() {
+        assert_eq!(scan_plain_run(b"", 0), 0);
+        assert_eq!(scan_plain_run(b"abc", 3), 3);
+    }
+}
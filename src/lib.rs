@@ -32,9 +32,17 @@
 //!
 //! See tests for specifics about what is considered a hashtag and what is not.
 //!
+//! `HashtagParser` only looks for hashtags. If you also need @mentions or $cashtags, use
+//! [`EntityParser`], which yields all three as a single [`Entity`] enum in one pass.
+//!
 //! # Features
 //!
-//! - `serde`: Enable `#[derive(Serialize)]` for [`Hashtag`].
+//! - `serde`: Enable `#[derive(Serialize)]` for [`Hashtag`], [`Entity`], [`Mention`], and
+//!   [`Cashtag`].
+//! - `simd`: Coalesce runs of ordinary text between entities into a single coarse token instead
+//!   of tokenizing one char at a time, which speeds up parsing of large, hashtag-sparse input.
+//!   Output and offsets are unaffected. The scan behind it checks a whole word of bytes at a
+//!   time with SWAR bit tricks rather than pulling in a `memchr`-style crate.
 
 #![deny(
     missing_docs,
@@ -50,12 +58,17 @@
 )]
 #![doc(html_root_url = "https://docs.rs/hashtag/1.0.0")]
 
-use std::{
-    borrow::Cow,
-    fmt,
-    iter::{once, Chain},
-    iter::{Enumerate, Peekable},
-};
+use std::{borrow::Cow, collections::HashSet, fmt};
+
+mod entity;
+#[cfg(feature = "simd")]
+mod fast_scan;
+mod streaming;
+
+pub use entity::{Cashtag, Entity, EntityParser, Mention};
+pub use streaming::StreamingHashtagParser;
+
+pub(crate) use entity::IsHashtagWhitespace;
 
 /// A hashtag found in some text. See documentation of top level module for more info.
 #[derive(Eq, PartialEq, Debug, Clone)]
@@ -95,344 +108,108 @@ impl<'a> AsRef<str> for Hashtag<'a> {
     }
 }
 
+impl<'a> Hashtag<'a> {
+    /// Renders `text` with every hashtag replaced by the markup `render` returns for it, leaving
+    /// everything else byte-for-byte untouched.
+    ///
+    /// `render` is called with each [`Hashtag`] in the order it's found; its return value is
+    /// spliced in where the original `#tag` was.
+    ///
+    /// ```
+    /// use hashtag::Hashtag;
+    ///
+    /// let html = Hashtag::render_links("check out #rust", |tag| {
+    ///     format!(r#"<a href="/tags/{0}">#{0}</a>"#, tag.text)
+    /// });
+    ///
+    /// assert_eq!(html, r#"check out <a href="/tags/rust">#rust</a>"#);
+    /// ```
+    pub fn render_links(text: &str, render: impl FnMut(&Hashtag<'_>) -> String) -> String {
+        render_links(text, render).0
+    }
+
+    /// Like [`Hashtag::render_links`], but also returns the deduplicated set of hashtag texts
+    /// (without the leading `#`) found in `text`, e.g. to show "tags mentioned in this post".
+    ///
+    /// ```
+    /// use hashtag::Hashtag;
+    /// use std::collections::HashSet;
+    ///
+    /// let (html, tags) = Hashtag::render_links_with_tags("#rust and #rust again", |tag| {
+    ///     format!("[{}]", tag.text)
+    /// });
+    ///
+    /// assert_eq!(html, "[rust] and [rust] again");
+    /// assert_eq!(tags, vec!["rust".to_string()].into_iter().collect::<HashSet<_>>());
+    /// ```
+    pub fn render_links_with_tags(
+        text: &str,
+        render: impl FnMut(&Hashtag<'_>) -> String,
+    ) -> (String, HashSet<String>) {
+        render_links(text, render)
+    }
+}
+
+/// Shared implementation behind [`Hashtag::render_links`] and [`Hashtag::render_links_with_tags`].
+///
+/// Walks the hashtags found in `text` in order, copying the gaps between them verbatim and
+/// splicing in `render`'s output in place of each one.
+fn render_links(
+    text: &str,
+    mut render: impl FnMut(&Hashtag<'_>) -> String,
+) -> (String, HashSet<String>) {
+    let mut html = String::with_capacity(text.len());
+    let mut tags = HashSet::new();
+    let mut hashtags = HashtagParser::new(text).peekable();
+    let mut last_byte = 0;
+
+    for (char_idx, (byte_idx, c)) in text.char_indices().enumerate() {
+        if hashtags.peek().is_some_and(|tag| tag.start == char_idx) {
+            html.push_str(&text[last_byte..byte_idx]);
+        }
+
+        if hashtags.peek().is_some_and(|tag| tag.end == char_idx) {
+            let hashtag = hashtags.next().expect("just peeked Some above");
+            tags.insert(hashtag.text.clone().into_owned());
+            html.push_str(&render(&hashtag));
+            last_byte = byte_idx + c.len_utf8();
+        }
+    }
+
+    html.push_str(&text[last_byte..]);
+    (html, tags)
+}
+
 /// A parser that finds hashtags in a string.
 ///
-/// Implements [`Iterator`] and yields [`Hashtag`]s.
+/// Implements [`Iterator`] and yields [`Hashtag`]s. This is a thin filter over [`EntityParser`]
+/// that only keeps [`Entity::Hashtag`]s; use `EntityParser` directly if you also need @mentions
+/// or $cashtags.
 #[derive(Debug)]
 pub struct HashtagParser<'a> {
-    whole_string: &'a str,
-    state: IterState<'a>,
-    done: bool,
+    entities: EntityParser<'a>,
 }
 
 impl<'a> HashtagParser<'a> {
     /// Create a new `HashtagParser` that will parse the given string.
     pub fn new(text: &'a str) -> Self {
         Self {
-            whole_string: text,
-            done: false,
-            state: IterState::Init,
+            entities: EntityParser::new(text),
         }
     }
 }
 
-#[derive(Debug)]
-enum IterState<'a> {
-    Init,
-    Parsing {
-        tokens: Peekable<Enumerate<TokenIter<'a>>>,
-        stm: ParsingStateMachine<'a>,
-    },
-}
-
 impl<'a> Iterator for HashtagParser<'a> {
     type Item = Hashtag<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.done {
-                return None;
-            }
-
-            match &mut self.state {
-                IterState::Init => {
-                    let tokens = tokenize(&self.whole_string).enumerate().peekable();
-                    let stm = ParsingStateMachine::new(&self.whole_string);
-                    self.state = IterState::Parsing { tokens, stm };
-                }
-                IterState::Parsing { tokens, stm } => {
-                    while let Some((i, token)) = tokens.next() {
-                        match token {
-                            Token::Hashtag => {
-                                if stm.parsing_hashtag() {
-                                    if tokens.peek().map(|(_, tok)| tok).is_end_of_hashtag() {
-                                        stm.reset_parsing_state();
-                                    } else {
-                                        stm.hashtag_token_seen_at(i - 1);
-                                    }
-                                }
-                            }
-
-                            Token::Whitespace => {
-                                let mut hashtag = None;
-
-                                if stm.parsing_hashtag() {
-                                    hashtag = stm.hashtag_finishes_at(i - 2);
-                                }
-
-                                if tokens.peek().map(|(_, tok)| tok).is_hashtag_token() {
-                                    stm.hashtag_incoming();
-                                }
-
-                                if let Some(hashtag) = hashtag.take() {
-                                    return Some(hashtag);
-                                }
-                            }
-
-                            Token::Char(c) => {
-                                if stm.parsing_hashtag() {
-                                    let mut hashtag = None;
-
-                                    if c.is_end_of_hashtag() {
-                                        hashtag = stm.hashtag_finishes_at(i - 2);
-                                    } else {
-                                        stm.consume_char(c);
-                                    }
-
-                                    if tokens.peek().map(|(_, tok)| tok).is_hashtag_token() {
-                                        hashtag = stm.hashtag_finishes_at(i - 1);
-                                        stm.hashtag_incoming();
-                                    }
-
-                                    if let Some(hashtag) = hashtag.take() {
-                                        return Some(hashtag);
-                                    }
-                                }
-                            }
-
-                            Token::StartOfString => {
-                                if tokens.peek().map(|(_, tok)| tok).is_hashtag_token() {
-                                    stm.hashtag_incoming();
-                                }
-                            }
-
-                            Token::EndOfString => {
-                                let hashtag = if stm.parsing_hashtag() {
-                                    stm.hashtag_finishes_at(i - 2)
-                                } else {
-                                    None
-                                };
-                                self.done = true;
-                                return hashtag;
-                            }
-                        }
-                    }
-                }
+        for entity in &mut self.entities {
+            if let Entity::Hashtag(hashtag) = entity {
+                return Some(hashtag);
             }
         }
+        None
     }
 }
 
 impl<'a> std::iter::FusedIterator for HashtagParser<'a> {}
-
-#[derive(Debug)]
-struct ParsingStateMachine<'a> {
-    consumed_anything: bool,
-    hashtag_buffer: String,
-    hashtag_start_index: usize,
-    parsing_hashtag: bool,
-    is_ascii: bool,
-    whole_string: &'a str,
-}
-
-impl<'a> ParsingStateMachine<'a> {
-    #[inline]
-    fn new(text: &'a str) -> ParsingStateMachine<'a> {
-        ParsingStateMachine {
-            parsing_hashtag: Self::default_parse_hashtag(),
-            hashtag_start_index: Self::default_hashtag_start_index(),
-            hashtag_buffer: String::new(),
-            consumed_anything: Self::default_consumed_anything(),
-            is_ascii: text.is_ascii(),
-            whole_string: text,
-        }
-    }
-
-    #[inline]
-    fn default_parse_hashtag() -> bool {
-        false
-    }
-
-    #[inline]
-    fn default_consumed_anything() -> bool {
-        false
-    }
-
-    #[inline]
-    fn default_hashtag_start_index() -> usize {
-        0
-    }
-
-    #[inline]
-    fn parsing_hashtag(&self) -> bool {
-        self.parsing_hashtag
-    }
-
-    #[inline]
-    fn hashtag_token_seen_at(&mut self, idx: usize) {
-        self.hashtag_start_index = idx;
-    }
-
-    #[inline]
-    fn hashtag_finishes_at(&mut self, idx: usize) -> Option<Hashtag<'a>> {
-        let hashtag = if self.consumed_anything {
-            let text = if self.is_ascii {
-                Cow::Borrowed(&self.whole_string[self.hashtag_start_index + 1..idx + 1])
-            } else {
-                Cow::Owned(self.hashtag_buffer.clone())
-            };
-
-            Some(Hashtag {
-                text,
-                start: self.hashtag_start_index,
-                end: idx,
-            })
-        } else {
-            None
-        };
-        self.reset_parsing_state();
-        hashtag
-    }
-
-    #[inline]
-    fn reset_parsing_state(&mut self) {
-        self.parsing_hashtag = Self::default_parse_hashtag();
-        self.hashtag_start_index = Self::default_hashtag_start_index();
-        self.hashtag_buffer.clear();
-        self.consumed_anything = Self::default_consumed_anything();
-    }
-
-    #[inline]
-    fn hashtag_incoming(&mut self) {
-        self.parsing_hashtag = true;
-    }
-
-    #[inline]
-    fn consume_char(&mut self, c: char) {
-        if !self.is_ascii {
-            self.hashtag_buffer.push(c);
-        }
-        self.consumed_anything = true;
-    }
-}
-#[derive(Eq, PartialEq, Debug)]
-enum Token {
-    Char(char),
-    Whitespace,
-    Hashtag,
-    StartOfString,
-    EndOfString,
-}
-
-trait IsHashtagToken {
-    fn is_hashtag_token(&self) -> bool;
-}
-
-impl IsHashtagToken for Token {
-    #[inline]
-    fn is_hashtag_token(&self) -> bool {
-        matches!(self, Token::Hashtag)
-    }
-}
-
-impl<'a, T> IsHashtagToken for Option<&'a T>
-where
-    T: IsHashtagToken,
-{
-    #[inline]
-    fn is_hashtag_token(&self) -> bool {
-        if let Some(x) = self {
-            x.is_hashtag_token()
-        } else {
-            false
-        }
-    }
-}
-
-type SingleToken = std::iter::Once<Token>;
-type TokensFromStr<'a> = std::iter::Map<std::str::Chars<'a>, fn(char) -> Token>;
-type TokenIter<'a> = Chain<Chain<SingleToken, TokensFromStr<'a>>, SingleToken>;
-
-#[inline]
-fn tokenize(text: &str) -> TokenIter<'_> {
-    once(Token::StartOfString)
-        .chain(text.chars().map(token_from_char as _))
-        .chain(once(Token::EndOfString))
-}
-
-#[inline]
-fn token_from_char(c: char) -> Token {
-    match c {
-        '#' => Token::Hashtag,
-        ' ' => Token::Whitespace,
-        '\n' => Token::Whitespace,
-        '\r' => Token::Whitespace,
-        '\t' => Token::Whitespace,
-        _ => Token::Char(c),
-    }
-}
-
-trait IsEndOfHashtag {
-    fn is_end_of_hashtag(&self) -> bool;
-}
-
-impl IsEndOfHashtag for char {
-    #[inline]
-    fn is_end_of_hashtag(&self) -> bool {
-        match self {
-            &'\'' | &' ' | &'%' | &'#' | &'\n' | &'"' | &'\t' | &'!' | &'@' | &'€' | &'$'
-            | &'^' | &'&' | &'*' | &'(' | &')' | &'\r' | &'.' | &',' | &'-' | &'<' | &'>'
-            | &'/' | &'\\' | &'|' | &'[' | &']' | &'{' | &'}' | &'`' | &'~' | &'=' | &'+'
-            | &';' | &'?' | &'£' | &'•' | &'´' | &':' => true,
-            &'_' => false,
-            _ => false,
-        }
-    }
-}
-
-impl IsEndOfHashtag for Token {
-    #[inline]
-    fn is_end_of_hashtag(&self) -> bool {
-        match self {
-            Token::Whitespace => true,
-            Token::Char(c) => c.is_end_of_hashtag(),
-            Token::EndOfString => true,
-            Token::Hashtag => false,
-            Token::StartOfString => false,
-        }
-    }
-}
-
-impl<'a, T> IsEndOfHashtag for Option<&'a T>
-where
-    T: IsEndOfHashtag,
-{
-    #[inline]
-    fn is_end_of_hashtag(&self) -> bool {
-        self.map(|x| x.is_end_of_hashtag()).unwrap_or(false)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_tokenization() {
-        let tokens: Vec<Token> = tokenize("text #foo").collect();
-        assert_eq!(
-            tokens,
-            vec![
-                Token::StartOfString,
-                Token::Char('t'),
-                Token::Char('e'),
-                Token::Char('x'),
-                Token::Char('t'),
-                Token::Whitespace,
-                Token::Hashtag,
-                Token::Char('f'),
-                Token::Char('o'),
-                Token::Char('o'),
-                Token::EndOfString,
-            ]
-        );
-    }
-
-    #[test]
-    fn test_tokenize_strings_with_emojis() {
-        assert_eq!(
-            tokenize("😀").collect::<Vec<_>>(),
-            vec![Token::StartOfString, Token::Char('😀'), Token::EndOfString,]
-        );
-    }
-}
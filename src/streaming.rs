@@ -0,0 +1,461 @@
+//! A [`HashtagParser`] alternative for input that comes from an [`io::Read`] rather than an
+//! in-memory `&str`, so very large inputs don't need to be buffered in full before parsing.
+
+use crate::{Hashtag, HashtagParser};
+use std::io::{self, Read};
+
+/// The default size, in bytes, of a [`StreamingHashtagParser`]'s internal buffer.
+const DEFAULT_CAPACITY: usize = 64 * 1024;
+
+/// A hashtag parser that reads from an [`io::Read`] in fixed-size chunks instead of requiring
+/// the whole input to be loaded into memory up front.
+///
+/// Because the internal buffer is refilled and shifted as parsing progresses, the yielded
+/// [`Hashtag`]s own their text rather than borrowing from the input.
+#[derive(Debug)]
+pub struct StreamingHashtagParser<R> {
+    reader: R,
+    buf: RingBuffer,
+    eof: bool,
+    done: bool,
+}
+
+impl<R: Read> StreamingHashtagParser<R> {
+    /// Create a new `StreamingHashtagParser` that reads from `reader` using a default-sized
+    /// internal buffer.
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(reader, DEFAULT_CAPACITY)
+    }
+
+    /// Create a new `StreamingHashtagParser` with a specific internal buffer capacity.
+    ///
+    /// A bigger capacity means fewer reads from `reader`, but a single hashtag can never be
+    /// longer than `capacity` bytes since it has to fit entirely inside the buffer before it's
+    /// emitted.
+    pub fn with_capacity(reader: R, capacity: usize) -> Self {
+        Self {
+            reader,
+            buf: RingBuffer::with_capacity(capacity),
+            eof: false,
+            done: false,
+        }
+    }
+
+    /// Keep refilling `self.buf` until it has a boundary to parse up to, or the reader is
+    /// exhausted.
+    ///
+    /// Returns the boundary together with whether the *next* window legitimately starts in an
+    /// armed state, equivalent to the implicit `Token::StartOfString` a fresh `HashtagParser`
+    /// grants its first char; see [`RingBuffer::armed_at_start`] for what that means and why it
+    /// isn't simply "did this cut land on whitespace".
+    fn fill_and_find_boundary(&mut self) -> io::Result<Option<(usize, bool)>> {
+        loop {
+            if let Some(boundary) = self.buf.safe_boundary() {
+                return Ok(Some((boundary, true)));
+            }
+
+            if self.eof {
+                if self.buf.raw_len > self.buf.valid_len {
+                    // The reader is exhausted but bytes past `valid_len` never validated as
+                    // UTF-8: an incomplete multibyte sequence truncated by genuine end of
+                    // stream, not just the current buffer boundary, so it can never finish. A
+                    // monolithic parse over the equivalent in-memory bytes would fail to even
+                    // construct a `str` at all; error here the same way `validate()` already
+                    // does for a byte sequence that can never become valid UTF-8, rather than
+                    // silently dropping the trailing bytes.
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "stream ended with an incomplete utf-8 sequence",
+                    ));
+                }
+
+                // Nothing left to read and no safe boundary was found; the rest of the buffer
+                // is as final as it'll ever be. There's no further window for the `false` here
+                // to matter to, so it's an arbitrary but harmless choice.
+                return Ok(Some((self.buf.valid_len, false)));
+            }
+
+            if self.buf.free_tail() == 0 {
+                if self.buf.valid_len == 0 {
+                    // Nothing in the full buffer has validated as UTF-8 and there's no room
+                    // left to read more: the leading bytes are an incomplete multibyte
+                    // sequence that can never finish because `capacity` is too small to ever
+                    // hold a whole char. There's nothing to compact away and no more data
+                    // coming, so without this we'd spin here forever.
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "buffer capacity is too small to hold a single UTF-8 character",
+                    ));
+                }
+
+                // The whole capacity is taken up by one unresolved token; best effort is to
+                // hand back whatever has been validated so far rather than growing forever.
+                // Whether `forced_boundary` held back a trailing trigger char or not, this cut
+                // runs through a stretch with no whitespace at all (`safe_boundary` already
+                // found none above), so there's no way to know from the text alone whether the
+                // next window's start should really be armed; `false` is the conservative
+                // choice. Capacity this much smaller than the input's structure is already a
+                // best-effort corner (see the "too small" error above), so this cut can still
+                // diverge from what a monolithic parse over the whole input would find.
+                return Ok(Some((self.buf.forced_boundary(), false)));
+            }
+
+            let n = self.reader.read(self.buf.free_tail_mut())?;
+
+            if n == 0 {
+                self.eof = true;
+            } else {
+                self.buf.raw_len += n;
+                self.buf.validate()?;
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for StreamingHashtagParser<R> {
+    type Item = io::Result<Hashtag<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+
+            if let Some(hashtag) = self.buf.pending.pop_front() {
+                return Some(Ok(hashtag));
+            }
+
+            let (boundary, boundary_is_safe) = match self.fill_and_find_boundary() {
+                Ok(boundary) => match boundary {
+                    Some(boundary) => boundary,
+                    None => continue,
+                },
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+
+            if boundary == 0 && self.eof {
+                self.done = true;
+                return None;
+            }
+
+            let text = std::str::from_utf8(&self.buf.data[..boundary])
+                .expect("boundary only ever lands on validated, and therefore valid, utf8");
+
+            // Only a window whose start follows genuine whitespace, or the true start of the
+            // stream, is legitimately equivalent to the implicit `Token::StartOfString` that a
+            // fresh `HashtagParser` always grants its first char. Otherwise, parsing `text` on
+            // its own would wrongly arm a trigger sitting right at its start even though the
+            // real preceding byte (ordinary punctuation, or a forced capacity cut) wouldn't have
+            // armed it in a single monolithic parse. Guard against that by prefixing a harmless,
+            // non-triggering character so the real first char of `text` is never treated as
+            // armed unless it actually is.
+            let armed = self.buf.armed_at_start;
+            let prefixed;
+            let parse_text = if armed {
+                text
+            } else {
+                prefixed = format!("a{text}");
+                prefixed.as_str()
+            };
+            let offset_correction = if armed { 0 } else { 1 };
+
+            let base_char_offset = self.buf.base_char_offset;
+            for hashtag in HashtagParser::new(parse_text) {
+                self.buf.pending.push_back(Hashtag {
+                    text: std::borrow::Cow::Owned(hashtag.text.into_owned()),
+                    start: base_char_offset + hashtag.start - offset_correction,
+                    end: base_char_offset + hashtag.end - offset_correction,
+                });
+            }
+
+            self.buf.base_char_offset += text.chars().count();
+            self.buf.armed_at_start = boundary_is_safe;
+            self.buf.compact(boundary);
+
+            if self.buf.pending.is_empty() && self.eof && self.buf.raw_len == 0 {
+                self.done = true;
+                return None;
+            }
+        }
+    }
+}
+
+/// A byte ring buffer tracking raw bytes read from the reader, the prefix of those bytes
+/// confirmed to be valid UTF-8, and hashtags already parsed out of the validated region but not
+/// yet returned to the caller.
+#[derive(Debug)]
+struct RingBuffer {
+    data: Vec<u8>,
+    /// Number of raw bytes read into `data`, valid or not.
+    raw_len: usize,
+    /// Number of bytes at the front of `data` confirmed to be valid UTF-8.
+    valid_len: usize,
+    /// Absolute char offset of `data[0]` in the overall stream, used to turn the relative
+    /// offsets `HashtagParser` reports into stream-absolute ones.
+    base_char_offset: usize,
+    /// Whether `data[0]` is legitimately equivalent to an implicit `StartOfString` that may arm
+    /// a trigger sitting right at the start of this window. True at the real start of the
+    /// stream and after a cut that landed right after genuine whitespace (a `safe_boundary` hit).
+    /// False after a forced or end-of-stream cut: those run through content with no whitespace to
+    /// vouch for what comes next, so treating the following window's first char as armed would
+    /// risk fabricating a hashtag the monolithic parser wouldn't recognize. This is the
+    /// conservative choice and can itself diverge from a monolithic parse in the rare case where
+    /// a forced cut lands right after a still-open trigger char (see `forced_boundary`) — an
+    /// accepted best-effort trade-off in that capacity-exhausted corner, not a correctness goal.
+    armed_at_start: bool,
+    /// Hashtags parsed out of a previous refill but not yet yielded.
+    pending: std::collections::VecDeque<Hashtag<'static>>,
+}
+
+impl RingBuffer {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: vec![0; capacity],
+            raw_len: 0,
+            valid_len: 0,
+            base_char_offset: 0,
+            armed_at_start: true,
+            pending: std::collections::VecDeque::new(),
+        }
+    }
+
+    fn free_tail(&self) -> usize {
+        self.data.len() - self.raw_len
+    }
+
+    fn free_tail_mut(&mut self) -> &mut [u8] {
+        let raw_len = self.raw_len;
+        &mut self.data[raw_len..]
+    }
+
+    /// Extend `valid_len` as far as possible into the not-yet-validated tail.
+    ///
+    /// Returns an error if the tail contains a byte sequence that can never become valid UTF-8
+    /// no matter how much more data follows, as opposed to one that's merely an incomplete
+    /// multibyte sequence truncated by the current buffer boundary.
+    fn validate(&mut self) -> io::Result<()> {
+        match std::str::from_utf8(&self.data[self.valid_len..self.raw_len]) {
+            Ok(_) => self.valid_len = self.raw_len,
+            Err(err) => {
+                if err.error_len().is_some() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid utf-8 sequence in stream: {err}"),
+                    ));
+                }
+                self.valid_len += err.valid_up_to();
+            }
+        }
+        Ok(())
+    }
+
+    /// The byte offset, within the validated region, up to which it's safe to finalize
+    /// hashtags: right after the last character that's known to unconditionally arm a following
+    /// trigger, the same way [`Token::StartOfString`](crate::entity::Token::StartOfString) does.
+    /// Anything past this point might still be a hashtag that continues into the next refill.
+    ///
+    /// Only whitespace qualifies here, *not* the wider `IsEndOfHashtag` punctuation set: per
+    /// `EntityParser`'s rules, ordinary punctuation only arms a trigger when
+    /// it's also closing a hashtag already in progress, not unconditionally the way whitespace
+    /// and start-of-string do. Cutting after arbitrary punctuation and resuming the next window
+    /// with a fresh `HashtagParser` would fabricate that unconditional arming (an implicit
+    /// `StartOfString`) where the monolithic parser wouldn't grant it, recognizing hashtags like
+    /// the `tag` in `wait,#tag` that the in-memory parser correctly rejects.
+    fn safe_boundary(&self) -> Option<usize> {
+        let valid_str = std::str::from_utf8(&self.data[..self.valid_len])
+            .expect("data[..valid_len] is always valid utf8 by construction");
+
+        valid_str
+            .char_indices()
+            .rev()
+            .find(|(_, c)| crate::IsHashtagWhitespace::is_hashtag_whitespace(c))
+            .map(|(i, c)| i + c.len_utf8())
+    }
+
+    /// Like [`Self::safe_boundary`], but for when the buffer is completely full and has no safe
+    /// boundary at all: capacity forces a cut right now, so this picks the best one available
+    /// instead of giving up and returning `valid_len` outright.
+    ///
+    /// If the validated region ends in `#`, that last byte is held back (regardless of how many
+    /// `#`s precede it — a `#` immediately followed by another `#` has an empty body and can
+    /// never become a real hashtag, so only the very last one is worth protecting) so a trigger
+    /// landing on the final byte of a full buffer still has a chance to pick up a body on the
+    /// next refill. If that single `#` is the entire valid region with nothing before it to free
+    /// up by cutting, holding it back would free no room at all, so this falls back to
+    /// `valid_len` and gives it up instead of spinning on the same empty cut forever.
+    fn forced_boundary(&self) -> usize {
+        let valid_str = std::str::from_utf8(&self.data[..self.valid_len])
+            .expect("data[..valid_len] is always valid utf8 by construction");
+
+        if !valid_str.ends_with('#') {
+            return self.valid_len;
+        }
+
+        let boundary = self.valid_len - 1;
+        if boundary == 0 {
+            self.valid_len
+        } else {
+            boundary
+        }
+    }
+
+    /// Discard everything before `boundary` by shifting the remaining raw bytes to the front.
+    fn compact(&mut self, boundary: usize) {
+        self.data.copy_within(boundary..self.raw_len, 0);
+        self.raw_len -= boundary;
+        self.valid_len -= boundary;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `Read` that hands out the given chunks one `read()` call at a time (splitting a chunk
+    /// further still if it doesn't fit in the caller's buffer), so tests can force a hashtag to
+    /// straddle a refill the way a slow pipe or socket would.
+    struct ChunkedReader {
+        chunks: std::collections::VecDeque<&'static [u8]>,
+    }
+
+    impl ChunkedReader {
+        fn new(chunks: &[&'static str]) -> Self {
+            Self {
+                chunks: chunks.iter().map(|s| s.as_bytes()).collect(),
+            }
+        }
+    }
+
+    impl Read for ChunkedReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let Some(chunk) = self.chunks.pop_front() else {
+                return Ok(0);
+            };
+            let n = chunk.len().min(buf.len());
+            buf[..n].copy_from_slice(&chunk[..n]);
+            if n < chunk.len() {
+                self.chunks.push_front(&chunk[n..]);
+            }
+            Ok(n)
+        }
+    }
+
+    fn collect_tags(
+        reader: impl Read,
+        capacity: usize,
+    ) -> io::Result<Vec<(usize, usize, String)>> {
+        StreamingHashtagParser::with_capacity(reader, capacity)
+            .map(|result| result.map(|h| (h.start, h.end, h.text.into_owned())))
+            .collect()
+    }
+
+    #[test]
+    fn whole_input_fits_in_one_refill() {
+        let reader = ChunkedReader::new(&["#rust is #awesome"]);
+        let tags = collect_tags(reader, 4096).unwrap();
+        assert_eq!(
+            tags,
+            vec![(0, 4, "rust".to_string()), (9, 16, "awesome".to_string())]
+        );
+    }
+
+    #[test]
+    fn hashtag_split_mid_body_across_a_read_call_is_still_parsed() {
+        let reader = ChunkedReader::new(&["prefix #rus", "t end"]);
+        let tags = collect_tags(reader, 4096).unwrap();
+        assert_eq!(tags, vec![(7, 11, "rust".to_string())]);
+    }
+
+    #[test]
+    fn hashtag_split_right_after_the_trigger_is_still_parsed() {
+        let reader = ChunkedReader::new(&["prefix #", "rust end"]);
+        let tags = collect_tags(reader, 4096).unwrap();
+        assert_eq!(tags, vec![(7, 11, "rust".to_string())]);
+    }
+
+    #[test]
+    fn trigger_landing_on_the_last_byte_of_a_full_buffer_does_not_fabricate_a_hashtag() {
+        // "aaaaaaa#" fills capacity 8 exactly with the trigger as its very last byte, leaving no
+        // safe boundary at all, so the buffer is forced to cut right after it. A monolithic parse
+        // of the whole input agrees: "aaaaaaa" isn't whitespace or start-of-string, so the `#`
+        // it's glued to never arms in the first place, and `rust` is just plain text.
+        let reader = ChunkedReader::new(&["aaaaaaa#", "rust"]);
+        let tags = collect_tags(reader, 8).unwrap();
+        assert_eq!(tags, Vec::<(usize, usize, String)>::new());
+    }
+
+    #[test]
+    fn trigger_landing_on_the_last_byte_of_a_buffer_full_of_triggers_is_a_best_effort_miss() {
+        // "#####" fills capacity 5 with nothing but triggers and no safe boundary at all. A
+        // monolithic parse of the whole input finds `(4, 8, "rust")`: the leading `#` is armed by
+        // true start-of-string and the chain of triggers stays self-sustaining through to the
+        // last one. But capacity 5 can't hold that whole chain plus "rust" in one window, and
+        // after the forced cut there's no whitespace anywhere in the buffer to vouch for the next
+        // window being armed, so this is an accepted best-effort divergence, not a correctness
+        // goal, in the same spirit as a hashtag simply being longer than `capacity`.
+        let reader = ChunkedReader::new(&["#####", "rust"]);
+        let tags = collect_tags(reader, 5).unwrap();
+        assert_eq!(tags, Vec::<(usize, usize, String)>::new());
+    }
+
+    #[test]
+    fn ordinary_punctuation_before_a_trigger_does_not_arm_it_across_a_refill() {
+        // A monolithic parse of "wait,#tag" finds nothing: "," isn't whitespace or
+        // start-of-string, so it never arms the trigger that follows it. A small capacity forces
+        // the window to be cut right after the "," (it's in `IsEndOfHashtag`'s punctuation set,
+        // but that's a distinct rule from what arms a trigger); the next window must not treat
+        // its first char as implicitly start-of-string just because a fresh `HashtagParser` run
+        // begins there.
+        let reader = ChunkedReader::new(&["wait,#tag"]);
+        let tags = collect_tags(reader, 6).unwrap();
+        assert_eq!(tags, Vec::<(usize, usize, String)>::new());
+    }
+
+    #[test]
+    fn capacity_too_small_for_one_char_errors_instead_of_hanging() {
+        let reader = ChunkedReader::new(&["😀"]);
+        let err = collect_tags(reader, 2).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn invalid_utf8_byte_errors_instead_of_hanging() {
+        struct BadReader {
+            emitted_bad: bool,
+        }
+
+        impl Read for BadReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if !self.emitted_bad {
+                    self.emitted_bad = true;
+                    buf[0] = 0xFF;
+                    return Ok(1);
+                }
+                for b in buf.iter_mut() {
+                    *b = b'a';
+                }
+                Ok(buf.len())
+            }
+        }
+
+        let reader = BadReader { emitted_bad: false };
+        let err = collect_tags(reader, 64).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn stream_ending_mid_utf8_sequence_errors_instead_of_dropping_the_tail() {
+        // A truncated 4-byte sequence (the lead byte of an emoji with its continuation bytes
+        // missing) with nothing more ever coming. A monolithic parse over the equivalent
+        // in-memory bytes would fail to even construct a `str`, so this must error too rather
+        // than silently finishing with whatever validated before it.
+        let mut bytes = b"some text #rust and then ".to_vec();
+        bytes.extend_from_slice(&[0xF0, 0x9F]);
+
+        let err = collect_tags(io::Cursor::new(bytes), 64).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
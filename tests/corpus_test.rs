@@ -0,0 +1,80 @@
+//! Runs `HashtagParser` over a large, real-world corpus purely to catch panics and
+//! offset-slicing bugs that only show up at scale, the way `tests/hashtag_tests.json` catches
+//! them on small hand-picked cases. Off by default since it downloads several megabytes on first
+//! run; enable with `cargo test --features download-corpus`.
+#![cfg(feature = "download-corpus")]
+
+use hashtag::HashtagParser;
+use std::{fs, io::Read, path::PathBuf};
+
+/// A large plain-text corpus of real-world English prose, chosen because it's big enough to
+/// stress offset arithmetic on realistic input without requiring any special license to mirror.
+const CORPUS_URL: &str = "https://norvig.com/big.txt";
+
+#[test]
+fn parsing_a_large_real_world_corpus_does_not_panic() {
+    let corpus = load_or_download_corpus();
+
+    let mut count = 0;
+    let mut prev_end = None;
+    for hashtag in HashtagParser::new(&corpus) {
+        assert!(hashtag.start <= hashtag.end);
+        if let Some(prev_end) = prev_end {
+            assert!(hashtag.start > prev_end);
+        }
+        prev_end = Some(hashtag.end);
+        count += 1;
+    }
+
+    println!(
+        "parsed {} hashtags out of a {}-byte corpus",
+        count,
+        corpus.len()
+    );
+}
+
+/// Loads the corpus from the fixture cache if it's already been downloaded, otherwise fetches
+/// and (when the archive is compressed) decompresses it, then caches it for next time.
+fn load_or_download_corpus() -> String {
+    let path = fixture_path();
+
+    if let Ok(mut file) = fs::File::open(&path) {
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)
+            .expect("cached corpus fixture is not valid utf8");
+        return contents;
+    }
+
+    let contents = fetch(CORPUS_URL);
+
+    fs::create_dir_all(path.parent().expect("fixture path always has a parent"))
+        .expect("failed to create fixture directory");
+    fs::write(&path, &contents).expect("failed to cache downloaded corpus");
+
+    contents
+}
+
+/// Downloads `url`, transparently decompressing it first if its extension says it's gzipped.
+fn fetch(url: &str) -> String {
+    let response = ureq::get(url).call().expect("failed to download corpus");
+
+    if url.ends_with(".gz") {
+        let mut decoder = flate2::read::GzDecoder::new(response.into_reader());
+        let mut contents = String::new();
+        decoder
+            .read_to_string(&mut contents)
+            .expect("corpus archive did not contain valid utf8");
+        contents
+    } else {
+        response
+            .into_string()
+            .expect("corpus response was not valid utf8")
+    }
+}
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("corpus.txt")
+}
@@ -0,0 +1,105 @@
+use hashtag::{HashtagParser, StreamingHashtagParser};
+use proptest::prelude::*;
+use std::io::Read;
+
+proptest! {
+    #[test]
+    fn hashtags_slice_back_to_themselves_and_never_overlap(text in arb_text()) {
+        let tags: Vec<_> = HashtagParser::new(&text).collect();
+
+        let mut prev_end: Option<usize> = None;
+        for tag in &tags {
+            prop_assert!(tag.start <= tag.end);
+
+            if let Some(prev_end) = prev_end {
+                prop_assert!(tag.start > prev_end, "hashtag ranges must be strictly increasing and non-overlapping");
+            }
+            prev_end = Some(tag.end);
+
+            let slice: String = text
+                .chars()
+                .skip(tag.start)
+                .take(tag.end - tag.start + 1)
+                .collect();
+
+            prop_assert!(slice.starts_with('#'));
+            prop_assert_eq!(&slice[1..], tag.text.as_ref());
+
+            let reparsed: Vec<_> = HashtagParser::new(&slice).collect();
+            prop_assert_eq!(reparsed.len(), 1);
+            prop_assert_eq!(reparsed[0].text.as_ref(), tag.text.as_ref());
+        }
+    }
+
+    /// `StreamingHashtagParser` reading from an `io::Read` in arbitrarily-sized chunks must find
+    /// exactly the same hashtags as `HashtagParser` does over the same text in memory. The buffer
+    /// capacity is kept generous relative to the text so this is only exercising the refill and
+    /// window-boundary bookkeeping, not the separate, already-documented best-effort behavior
+    /// that kicks in once capacity is too small to hold a whole hashtag (or the chain of triggers
+    /// leading into one) at once.
+    #[test]
+    fn streaming_parser_agrees_with_the_in_memory_parser(
+        text in arb_text(),
+        chunk_sizes in prop::collection::vec(1usize..16, 1..5),
+    ) {
+        let expected: Vec<_> = HashtagParser::new(&text)
+            .map(|h| (h.start, h.end, h.text.into_owned()))
+            .collect();
+
+        let capacity = text.len() + 64;
+        let reader = ChunkedReader { data: text.as_bytes(), pos: 0, chunk_sizes, next_chunk: 0 };
+        let actual: Vec<_> = StreamingHashtagParser::with_capacity(reader, capacity)
+            .map(|h| {
+                let h = h.expect("reader never errors and capacity is always large enough");
+                (h.start, h.end, h.text.into_owned())
+            })
+            .collect();
+
+        prop_assert_eq!(actual, expected);
+    }
+}
+
+/// A `Read` that hands `data` back in a cycle of caller-chosen chunk sizes instead of all at
+/// once, so the property test above exercises the same mid-hashtag, mid-trigger, and
+/// right-after-whitespace refill splits as the hand-written unit tests in `streaming.rs`, but at
+/// random split points instead of the hand-picked ones.
+struct ChunkedReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    chunk_sizes: Vec<usize>,
+    next_chunk: usize,
+}
+
+impl<'a> Read for ChunkedReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let chunk_size = self.chunk_sizes[self.next_chunk % self.chunk_sizes.len()];
+        self.next_chunk += 1;
+
+        let n = chunk_size.min(buf.len()).min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Strings built from a mix of `#`, whitespace, the full end-of-hashtag punctuation set, ASCII
+/// letters, and arbitrary (often multibyte) chars, so generated cases exercise the same
+/// boundaries as the hand-written unit tests but at random.
+fn arb_text() -> impl Strategy<Value = String> {
+    let interesting_char = prop_oneof![
+        3 => Just('#'),
+        2 => Just(' '),
+        1 => Just('\n'),
+        1 => Just('\t'),
+        2 => prop::char::range('a', 'z'),
+        2 => prop::char::range('A', 'Z'),
+        1 => prop_oneof![
+            Just('\''), Just('%'), Just('"'), Just('!'), Just('@'), Just('$'), Just('^'),
+            Just('&'), Just('*'), Just('('), Just(')'), Just('.'), Just(','), Just('-'),
+            Just('<'), Just('>'), Just('/'), Just('_'),
+        ],
+        1 => any::<char>(),
+    ];
+
+    prop::collection::vec(interesting_char, 0..200).prop_map(|chars| chars.into_iter().collect())
+}